@@ -1,7 +1,12 @@
+mod rng;
+mod sparse;
+#[cfg(feature = "profiling")]
+mod timer;
 mod utils;
 
+pub use sparse::SparseUniverse;
+
 use std::fmt::Display;
-use std::time::Duration;
 
 use wasm_bindgen::prelude::*;
 
@@ -23,38 +28,162 @@ pub enum Cell {
     Alive = 1,
 }
 
+impl Cell {
+    fn toggle(&mut self) {
+        *self = match *self {
+            Cell::Dead => Cell::Alive,
+            Cell::Alive => Cell::Dead,
+        };
+    }
+}
+
+/// A life-like cellular automaton rule in `B.../S...` notation.
+///
+/// `birth` and `survival` are bitmasks where bit `n` set means "a cell with
+/// `n` live neighbors is born" / "stays alive", respectively.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ruleset {
+    birth: u16,
+    survival: u16,
+}
+
+impl Ruleset {
+    /// Conway's standard rule: birth on 3 neighbors, survival on 2 or 3.
+    pub const CONWAY: Ruleset = Ruleset {
+        birth: 1 << 3,
+        survival: (1 << 2) | (1 << 3),
+    };
+
+    /// Parses a rule string such as `"B3/S23"`, `"B36/S23"` (HighLife), or
+    /// `"B2/S"` (Seeds). Returns an error if the `B`/`S` parts are missing
+    /// or a neighbor count is out of the representable `0..=8` range.
+    pub fn parse(rule: &str) -> Result<Ruleset, String> {
+        let (b_part, s_part) = rule
+            .split_once('/')
+            .ok_or_else(|| format!("ruleset '{rule}' is missing the '/' separator"))?;
+
+        let b_digits = b_part
+            .strip_prefix('B')
+            .ok_or_else(|| format!("ruleset '{rule}' is missing the 'B' prefix"))?;
+        let s_digits = s_part
+            .strip_prefix('S')
+            .ok_or_else(|| format!("ruleset '{rule}' is missing the 'S' prefix"))?;
+
+        Ok(Ruleset {
+            birth: Self::parse_digits(b_digits)?,
+            survival: Self::parse_digits(s_digits)?,
+        })
+    }
+
+    fn parse_digits(digits: &str) -> Result<u16, String> {
+        let mut mask = 0u16;
+        for c in digits.chars() {
+            let n = c
+                .to_digit(10)
+                .ok_or_else(|| format!("'{c}' is not a valid neighbor count"))?;
+            if n > 8 {
+                return Err(format!("neighbor count {n} is out of range 0..=8"));
+            }
+            mask |= 1 << n;
+        }
+        Ok(mask)
+    }
+
+    fn births_on(&self, live_neighbors: u8) -> bool {
+        self.birth & (1 << live_neighbors) != 0
+    }
+
+    fn survives_on(&self, live_neighbors: u8) -> bool {
+        self.survival & (1 << live_neighbors) != 0
+    }
+}
+
+impl Default for Ruleset {
+    fn default() -> Self {
+        Ruleset::CONWAY
+    }
+}
+
+/// How `live_neighbor_count` treats positions outside `0..width`/`0..height`.
+#[wasm_bindgen]
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Boundary {
+    /// The grid wraps around, so the top/bottom and left/right edges are
+    /// neighbors of each other.
+    #[default]
+    Toroidal = 0,
+    /// Positions outside the grid contribute 0, as if surrounded by
+    /// permanently dead cells.
+    Dead = 1,
+}
+
 #[wasm_bindgen]
 pub struct Universe {
     width: u32,
     height: u32,
     cells: Vec<Cell>,
+    scratch: Vec<Cell>,
+    rule: Ruleset,
+    boundary: Boundary,
 }
 
 #[wasm_bindgen]
 impl Universe {
+    /// Replaces the active ruleset, parsed from `B.../S...` notation.
+    pub fn set_rule(&mut self, rule: &str) -> Result<(), JsValue> {
+        self.rule = Ruleset::parse(rule).map_err(|e| JsValue::from_str(&e))?;
+        Ok(())
+    }
+
+    /// Replaces how neighbor lookups treat the grid's edges.
+    pub fn set_boundary(&mut self, boundary: Boundary) {
+        self.boundary = boundary;
+    }
+
     fn get_index(&self, row: u32, column: u32) -> usize {
         (row * self.width + column) as usize
     }
 
     fn live_neighbor_count(&self, row: u32, column: u32) -> u8 {
         let mut count = 0;
-        for delta_row in [self.height - 1, 0, 1].iter().cloned() {
-            for delta_col in [self.width - 1, 0, 1].iter().cloned() {
+        for delta_row in [-1i32, 0, 1] {
+            for delta_col in [-1i32, 0, 1] {
                 if delta_row == 0 && delta_col == 0 {
                     continue;
                 }
 
-                let neighbor_row = (row + delta_row) % self.height;
-                let neighbor_col = (column + delta_col) % self.width;
-                let idx = self.get_index(neighbor_row, neighbor_col);
-                count += self.cells[idx] as u8;
+                match self.boundary {
+                    Boundary::Toroidal => {
+                        let neighbor_row =
+                            (row as i32 + delta_row).rem_euclid(self.height as i32) as u32;
+                        let neighbor_col =
+                            (column as i32 + delta_col).rem_euclid(self.width as i32) as u32;
+                        let idx = self.get_index(neighbor_row, neighbor_col);
+                        count += self.cells[idx] as u8;
+                    }
+                    Boundary::Dead => {
+                        let neighbor_row = row as i32 + delta_row;
+                        let neighbor_col = column as i32 + delta_col;
+                        if neighbor_row < 0
+                            || neighbor_row >= self.height as i32
+                            || neighbor_col < 0
+                            || neighbor_col >= self.width as i32
+                        {
+                            continue;
+                        }
+                        let idx = self.get_index(neighbor_row as u32, neighbor_col as u32);
+                        count += self.cells[idx] as u8;
+                    }
+                }
             }
         }
         count
     }
 
     pub fn tick(&mut self) {
-        let mut next = self.cells.clone();
+        #[cfg(feature = "profiling")]
+        let _timer = crate::timer::Timer::new("Universe::tick");
 
         for row in 0..self.height {
             for col in 0..self.width {
@@ -62,19 +191,23 @@ impl Universe {
                 let cell = self.cells[idx];
                 let live_neighbors = self.live_neighbor_count(row, col);
 
-                let next_cell = match (cell, live_neighbors) {
-                    (Cell::Alive, x) if x < 2 => Cell::Dead,
-                    (Cell::Alive, x) if x == 2 || x == 3 => Cell::Alive,
-                    (Cell::Alive, x) if x > 3 => Cell::Dead,
-                    (Cell::Dead, x) if x == 3 => Cell::Alive,
-                    (otherwise, _) => otherwise,
+                let next_cell = if cell == Cell::Alive {
+                    if self.rule.survives_on(live_neighbors) {
+                        Cell::Alive
+                    } else {
+                        Cell::Dead
+                    }
+                } else if self.rule.births_on(live_neighbors) {
+                    Cell::Alive
+                } else {
+                    Cell::Dead
                 };
 
-                next[idx] = next_cell;
+                self.scratch[idx] = next_cell;
             }
         }
 
-        self.cells = next;
+        std::mem::swap(&mut self.cells, &mut self.scratch);
     }
 
     pub fn new() -> Universe {
@@ -91,10 +224,240 @@ impl Universe {
             })
             .collect();
 
+        let scratch = vec![Cell::Dead; (width * height) as usize];
+
+        Universe {
+            width,
+            height,
+            cells,
+            scratch,
+            rule: Ruleset::default(),
+            boundary: Boundary::default(),
+        }
+    }
+
+    /// Builds a `width`x`height` universe, seeding each cell alive with
+    /// probability `density` from a `seed`-ed PRNG.
+    pub fn new_random(width: u32, height: u32, density: f64, seed: u64) -> Universe {
+        let mut rng = rng::SplitMix64::new(seed);
+        let cells = (0..width * height)
+            .map(|_| {
+                if rng.next_f64() < density {
+                    Cell::Alive
+                } else {
+                    Cell::Dead
+                }
+            })
+            .collect();
+
+        let scratch = vec![Cell::Dead; (width * height) as usize];
+
         Universe {
             width,
             height,
             cells,
+            scratch,
+            rule: Ruleset::default(),
+            boundary: Boundary::default(),
+        }
+    }
+
+    /// Re-seeds this universe's cells in place, like `new_random`.
+    pub fn randomize(&mut self, density: f64, seed: u64) {
+        let mut rng = rng::SplitMix64::new(seed);
+        for cell in self.cells.iter_mut() {
+            *cell = if rng.next_f64() < density {
+                Cell::Alive
+            } else {
+                Cell::Dead
+            };
+        }
+    }
+
+    /// Builds a `Universe` from plaintext format: `.`, space, and `0` are
+    /// dead cells, anything else printable is alive. Width is derived from
+    /// the longest line, height from the line count, and shorter lines are
+    /// padded with dead cells.
+    pub fn from_plaintext(pattern: &str) -> Universe {
+        let lines: Vec<Vec<char>> = pattern.lines().map(|line| line.chars().collect()).collect();
+        let width = lines.iter().map(|line| line.len()).max().unwrap_or(0) as u32;
+        let height = lines.len() as u32;
+
+        let mut cells = Vec::with_capacity((width * height) as usize);
+        for line in &lines {
+            for col in 0..width as usize {
+                let c = line.get(col).copied().unwrap_or('.');
+                cells.push(match c {
+                    '.' | ' ' | '0' => Cell::Dead,
+                    _ => Cell::Alive,
+                });
+            }
+        }
+
+        let scratch = vec![Cell::Dead; cells.len()];
+
+        Universe {
+            width,
+            height,
+            cells,
+            scratch,
+            rule: Ruleset::default(),
+            boundary: Boundary::default(),
+        }
+    }
+
+    /// Builds a `Universe` from RLE format: an `x = W, y = H` header
+    /// followed by a run-length body where `<count>b` is dead cells,
+    /// `<count>o` is live cells, `$` ends a row, and `!` terminates the
+    /// pattern. A missing count defaults to 1.
+    pub fn from_rle(pattern: &str) -> Result<Universe, JsValue> {
+        Self::from_rle_impl(pattern).map_err(|e| JsValue::from_str(&e))
+    }
+
+    /// Parsing behind `from_rle`, kept on a plain `String` error so it can be
+    /// unit-tested directly without going through the `JsValue` extern.
+    fn from_rle_impl(pattern: &str) -> Result<Universe, String> {
+        let mut width = 0u32;
+        let mut height = 0u32;
+        let mut body = String::new();
+
+        for line in pattern.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if line.starts_with('x') {
+                for part in line.split(',') {
+                    if let Some((key, value)) = part.split_once('=') {
+                        match key.trim() {
+                            "x" => {
+                                width = value
+                                    .trim()
+                                    .parse()
+                                    .map_err(|_| "invalid width in RLE header".to_string())?
+                            }
+                            "y" => {
+                                height = value
+                                    .trim()
+                                    .parse()
+                                    .map_err(|_| "invalid height in RLE header".to_string())?
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            } else {
+                body.push_str(line);
+            }
+        }
+
+        if width == 0 || height == 0 {
+            return Err("RLE pattern is missing an 'x = W, y = H' header".to_string());
+        }
+
+        let mut cells = vec![Cell::Dead; (width * height) as usize];
+        let mut row = 0u32;
+        let mut col = 0u32;
+        let mut count = 0u32;
+
+        for c in body.chars() {
+            match c {
+                '0'..='9' => {
+                    count = count
+                        .checked_mul(10)
+                        .and_then(|n| n.checked_add(c.to_digit(10).unwrap()))
+                        .ok_or_else(|| "RLE run-length count overflows u32".to_string())?;
+                }
+                'b' | 'o' => {
+                    let run = if count == 0 { 1 } else { count };
+                    // Clamp to what's actually left in the declared grid, so a
+                    // huge or typo'd run-length count can't spin the loop
+                    // billions of times over cells that will never be written.
+                    let total = width as u64 * height as u64;
+                    let written = (row as u64).saturating_mul(width as u64) + col as u64;
+                    let run = (run as u64).min(total.saturating_sub(written)) as u32;
+                    for _ in 0..run {
+                        if row < height && col < width {
+                            let idx = (row * width + col) as usize;
+                            cells[idx] = if c == 'o' { Cell::Alive } else { Cell::Dead };
+                        }
+                        col += 1;
+                    }
+                    count = 0;
+                }
+                '$' => {
+                    let skip = if count == 0 { 1 } else { count };
+                    row = row
+                        .checked_add(skip)
+                        .ok_or_else(|| "RLE row-skip count overflows u32".to_string())?;
+                    col = 0;
+                    count = 0;
+                }
+                '!' => break,
+                _ => {}
+            }
+        }
+
+        let scratch = vec![Cell::Dead; cells.len()];
+
+        Ok(Universe {
+            width,
+            height,
+            cells,
+            scratch,
+            rule: Ruleset::default(),
+            boundary: Boundary::default(),
+        })
+    }
+
+    /// Flips a single cell between alive and dead. Used to support
+    /// click-to-edit front-ends. `row`/`column` wrap at the grid edges, like
+    /// `insert_pattern`, so an out-of-range coordinate never indexes past the
+    /// cell buffer or lands in the wrong row.
+    ///
+    /// No-op on a zero-width or zero-height universe (e.g. one built from an
+    /// empty pattern), since there is no cell to wrap the coordinates onto.
+    pub fn toggle_cell(&mut self, row: u32, column: u32) {
+        if self.width == 0 || self.height == 0 {
+            return;
+        }
+        let idx = self.get_index(row % self.height, column % self.width);
+        self.cells[idx].toggle();
+    }
+
+    /// Sets a single cell to a specific state. `row`/`column` wrap at the
+    /// grid edges, like `insert_pattern`.
+    ///
+    /// No-op on a zero-width or zero-height universe (e.g. one built from an
+    /// empty pattern), since there is no cell to wrap the coordinates onto.
+    pub fn set_cell(&mut self, row: u32, column: u32, alive: bool) {
+        if self.width == 0 || self.height == 0 {
+            return;
+        }
+        let idx = self.get_index(row % self.height, column % self.width);
+        self.cells[idx] = if alive { Cell::Alive } else { Cell::Dead };
+    }
+
+    /// Stamps a glider with its top-left corner at `(row, column)`.
+    pub fn insert_glider(&mut self, row: u32, column: u32) {
+        self.insert_pattern(row, column, ".#.\n..#\n###");
+    }
+
+    /// Stamps a plaintext-format pattern with its top-left corner at
+    /// `(row, column)`, wrapping at the grid edges. Only live cells in the
+    /// pattern are written; dead cells in the pattern leave the existing
+    /// cell untouched.
+    pub fn insert_pattern(&mut self, row: u32, column: u32, pattern: &str) {
+        for (delta_row, line) in pattern.lines().enumerate() {
+            for (delta_col, c) in line.chars().enumerate() {
+                if matches!(c, '.' | ' ' | '0') {
+                    continue;
+                }
+                let r = (row + delta_row as u32) % self.height;
+                let col = (column + delta_col as u32) % self.width;
+                let idx = self.get_index(r, col);
+                self.cells[idx] = Cell::Alive;
+            }
         }
     }
 
@@ -102,6 +465,32 @@ impl Universe {
         self.to_string()
     }
 
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Raw pointer into the live cell buffer, for JS to read directly out of
+    /// the WASM linear memory as a `Uint8Array` instead of paying for a
+    /// serialize-a-string-per-frame `render()` call.
+    ///
+    /// `tick()` swaps the cell buffer with its scratch buffer, so this
+    /// pointer is only valid for the generation it was fetched in — re-call
+    /// `cells_ptr()` after every `tick()` rather than caching the address.
+    pub fn cells_ptr(&self) -> *const Cell {
+        self.cells.as_ptr()
+    }
+
+}
+
+impl Universe {
+    /// Currently-live cell buffer, as a safe slice.
+    pub fn cells(&self) -> &[Cell] {
+        &self.cells
+    }
 }
 
 impl Display for Universe {
@@ -121,16 +510,145 @@ impl Display for Universe {
 #[cfg(test)]
 mod tests {
 
-    use std::thread::sleep;
-
     use super::*;
 
+    #[test]
+    pub fn test_ruleset_parse_conway() -> () {
+        assert_eq!(Ruleset::parse("B3/S23").unwrap(), Ruleset::CONWAY);
+    }
+
+    #[test]
+    pub fn test_ruleset_parse_rejects_malformed_strings() -> () {
+        assert!(Ruleset::parse("3/S23").is_err());
+        assert!(Ruleset::parse("B3/23").is_err());
+        assert!(Ruleset::parse("B9/S23").is_err());
+    }
+
+    #[test]
+    pub fn test_from_plaintext_parses_glider() -> () {
+        let universe = Universe::from_plaintext(".#.\n..#\n###");
+        assert_eq!(universe.width, 3);
+        assert_eq!(universe.height, 3);
+        assert_eq!(
+            universe.cells,
+            vec![
+                Cell::Dead, Cell::Alive, Cell::Dead,
+                Cell::Dead, Cell::Dead, Cell::Alive,
+                Cell::Alive, Cell::Alive, Cell::Alive,
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_from_rle_parses_glider() -> () {
+        let universe = Universe::from_rle_impl("x = 3, y = 3, rule = B3/S23\nbo$2bo$3o!").unwrap();
+        assert_eq!(universe.width, 3);
+        assert_eq!(universe.height, 3);
+        assert_eq!(
+            universe.cells,
+            vec![
+                Cell::Dead, Cell::Alive, Cell::Dead,
+                Cell::Dead, Cell::Dead, Cell::Alive,
+                Cell::Alive, Cell::Alive, Cell::Alive,
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_from_rle_rejects_missing_header() -> () {
+        assert!(Universe::from_rle_impl("bo$2bo$3o!").is_err());
+    }
+
+    #[test]
+    pub fn test_from_rle_rejects_overflowing_run_length() -> () {
+        assert!(Universe::from_rle_impl("x = 3, y = 3\n99999999999b!").is_err());
+    }
+
+    #[test]
+    pub fn test_from_rle_clamps_run_length_to_grid_size() -> () {
+        let universe =
+            Universe::from_rle_impl("x = 3, y = 3\n4294967295o!").unwrap();
+        assert_eq!(universe.cells, vec![Cell::Alive; 9]);
+    }
+
+    #[test]
+    pub fn test_from_rle_rejects_overflowing_row_skip() -> () {
+        assert!(Universe::from_rle_impl("x = 3, y = 3\nbo$4294967295$3o!").is_err());
+    }
+
+    #[test]
+    pub fn test_new_random_is_reproducible_for_same_seed() -> () {
+        let a = Universe::new_random(8, 8, 0.5, 42);
+        let b = Universe::new_random(8, 8, 0.5, 42);
+        assert_eq!(a.cells, b.cells);
+    }
+
+    #[test]
+    pub fn test_randomize_reseeds_in_place() -> () {
+        let mut universe = Universe::new_random(8, 8, 0.5, 1);
+        let reference = Universe::new_random(8, 8, 0.5, 99);
+
+        universe.randomize(0.5, 99);
+
+        assert_eq!(universe.cells, reference.cells);
+    }
+
+    #[test]
+    pub fn test_toggle_cell_flips_state() -> () {
+        let mut universe = Universe {
+            width: 5,
+            height: 5,
+            cells: vec![Cell::Dead; 25],
+            scratch: vec![Cell::Dead; 25],
+            rule: Ruleset::default(),
+            boundary: Boundary::default(),
+        };
+        universe.toggle_cell(1, 2);
+        assert_eq!(universe.cells[7], Cell::Alive);
+        universe.toggle_cell(1, 2);
+        assert_eq!(universe.cells[7], Cell::Dead);
+    }
+
+    #[test]
+    pub fn test_toggle_and_set_cell_are_noops_on_empty_universe() -> () {
+        let mut universe = Universe::from_plaintext("");
+        universe.toggle_cell(0, 0);
+        universe.set_cell(0, 0, true);
+        assert!(universe.cells.is_empty());
+    }
+
+    #[test]
+    pub fn test_insert_glider_wraps_at_edges() -> () {
+        let mut universe = Universe {
+            width: 3,
+            height: 3,
+            cells: vec![Cell::Dead; 9],
+            scratch: vec![Cell::Dead; 9],
+            rule: Ruleset::default(),
+            boundary: Boundary::default(),
+        };
+        // Stamped at (1, 1), the glider's bottom row and right column wrap
+        // around to row 0 and column 0.
+        universe.insert_glider(1, 1);
+        assert_eq!(
+            universe.cells,
+            vec![
+                Cell::Alive, Cell::Alive, Cell::Alive,
+                Cell::Dead, Cell::Dead, Cell::Alive,
+                Cell::Alive, Cell::Dead, Cell::Dead,
+            ]
+        );
+    }
+
     #[test]
     pub fn test_get_index_work_as_expected() -> () {
         let mut universe = Universe {
             width: 5,
             height: 5,
             cells: vec![Cell::Dead; 25],
+            scratch: vec![Cell::Dead; 25],
+            rule: Ruleset::default(),
+            boundary: Boundary::default(),
         };
         assert_eq!(7, universe.get_index(1, 2));
     }
@@ -141,6 +659,9 @@ mod tests {
             width: 5,
             height: 5,
             cells: vec![Cell::Dead; 25],
+            scratch: vec![Cell::Dead; 25],
+            rule: Ruleset::default(),
+            boundary: Boundary::default(),
         };
         for i in 1..6 {
             for j in 1..6 {
@@ -149,11 +670,37 @@ mod tests {
         }
     }
 
+    #[test]
+    pub fn test_dead_boundary_does_not_wrap_at_corner() -> () {
+        let mut cells = vec![Cell::Dead; 9];
+        cells[8] = Cell::Alive; // bottom-right corner, (row 2, col 2)
+
+        let mut universe = Universe {
+            width: 3,
+            height: 3,
+            cells,
+            scratch: vec![Cell::Dead; 9],
+            rule: Ruleset::default(),
+            boundary: Boundary::Toroidal,
+        };
+
+        // Toroidal wraps, so the corner at (2, 2) is a neighbor of (0, 0).
+        assert_eq!(universe.live_neighbor_count(0, 0), 1);
+
+        universe.boundary = Boundary::Dead;
+
+        // Dead treats anything past the edge as dead, so it no longer counts.
+        assert_eq!(universe.live_neighbor_count(0, 0), 0);
+    }
+
     #[test]
     pub fn test_display_universe_tick() -> () {
         let mut universe = Universe {
             width: 5,
             height: 5,
+            rule: Ruleset::default(),
+            boundary: Boundary::default(),
+            scratch: vec![Cell::Dead; 25],
             cells: vec![
                 Cell::Dead,
                 Cell::Dead,
@@ -187,11 +734,30 @@ mod tests {
             ],
         };
 
-        'infinite: loop {
-            println!("{universe}");
-            universe.tick();
-            sleep(Duration::from_millis(500));
-        }
-        
+        // A vertical blinker flips to horizontal after one tick...
+        universe.tick();
+        assert_eq!(
+            universe.cells(),
+            &[
+                Cell::Dead, Cell::Dead, Cell::Dead, Cell::Dead, Cell::Dead,
+                Cell::Dead, Cell::Dead, Cell::Dead, Cell::Dead, Cell::Dead,
+                Cell::Dead, Cell::Alive, Cell::Alive, Cell::Alive, Cell::Dead,
+                Cell::Dead, Cell::Dead, Cell::Dead, Cell::Dead, Cell::Dead,
+                Cell::Dead, Cell::Dead, Cell::Dead, Cell::Dead, Cell::Dead,
+            ][..]
+        );
+
+        // ...and back to vertical after a second.
+        universe.tick();
+        assert_eq!(
+            universe.cells(),
+            &[
+                Cell::Dead, Cell::Dead, Cell::Dead, Cell::Dead, Cell::Dead,
+                Cell::Dead, Cell::Dead, Cell::Alive, Cell::Dead, Cell::Dead,
+                Cell::Dead, Cell::Dead, Cell::Alive, Cell::Dead, Cell::Dead,
+                Cell::Dead, Cell::Dead, Cell::Alive, Cell::Dead, Cell::Dead,
+                Cell::Dead, Cell::Dead, Cell::Dead, Cell::Dead, Cell::Dead,
+            ][..]
+        );
     }
 }
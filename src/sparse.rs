@@ -0,0 +1,104 @@
+use std::collections::{BTreeSet, HashMap};
+
+use wasm_bindgen::prelude::*;
+
+/// An unbounded Game of Life board that stores only live cells.
+#[wasm_bindgen]
+pub struct SparseUniverse {
+    cells: BTreeSet<(i64, i64)>,
+}
+
+impl Default for SparseUniverse {
+    fn default() -> Self {
+        SparseUniverse {
+            cells: BTreeSet::new(),
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl SparseUniverse {
+    pub fn new() -> SparseUniverse {
+        SparseUniverse::default()
+    }
+
+    pub fn set_cell(&mut self, x: i64, y: i64, alive: bool) {
+        if alive {
+            self.cells.insert((x, y));
+        } else {
+            self.cells.remove(&(x, y));
+        }
+    }
+
+    pub fn population(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn tick(&mut self) {
+        let mut neighbor_counts: HashMap<(i64, i64), u8> = HashMap::new();
+
+        for &(x, y) in &self.cells {
+            for delta_y in -1..=1 {
+                for delta_x in -1..=1 {
+                    if delta_x == 0 && delta_y == 0 {
+                        continue;
+                    }
+                    *neighbor_counts.entry((x + delta_x, y + delta_y)).or_insert(0) += 1;
+                }
+            }
+        }
+
+        let mut next = BTreeSet::new();
+        for (&coord, &count) in &neighbor_counts {
+            if count == 3 || (count == 2 && self.cells.contains(&coord)) {
+                next.insert(coord);
+            }
+        }
+
+        self.cells = next;
+    }
+
+    /// Renders the live cells within `[min_x, max_x] x [min_y, max_y]` using
+    /// the same glyphs as `Universe::render`.
+    pub fn render_window(&self, min_x: i64, min_y: i64, max_x: i64, max_y: i64) -> String {
+        let mut out = String::new();
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                let symbol = if self.cells.contains(&(x, y)) { '◼' } else { '◻' };
+                out.push(symbol);
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_blinker_oscillates() {
+        let mut universe = SparseUniverse::new();
+        universe.set_cell(0, 1, true);
+        universe.set_cell(1, 1, true);
+        universe.set_cell(2, 1, true);
+
+        universe.tick();
+
+        assert_eq!(universe.population(), 3);
+        assert!(universe.cells.contains(&(1, 0)));
+        assert!(universe.cells.contains(&(1, 1)));
+        assert!(universe.cells.contains(&(1, 2)));
+    }
+
+    #[test]
+    pub fn test_lonely_cell_dies() {
+        let mut universe = SparseUniverse::new();
+        universe.set_cell(5, 5, true);
+
+        universe.tick();
+
+        assert_eq!(universe.population(), 0);
+    }
+}